@@ -0,0 +1,514 @@
+//! The Copy Circuit implements a log-derivative (logUp) lookup argument meant
+//! to move the per-byte `memory_lookup`/`tx_context_lookup` work out of the
+//! EVM circuit for multi-step copy gadgets (e.g. `CopyToMemoryGadget`).
+//! Instead of emitting one lookup per copied byte, a consuming gadget emits a
+//! running-fingerprint accumulator (`copy_acc`/`copy_acc_ext`) and, when its
+//! copy finishes, anchors that accumulator to a real row this circuit
+//! assigned via [`CopyCircuitConfig::lookup_run_total`]'s `lookup_any` —
+//! this circuit proving that row matches a contiguous run of
+//! `(tag, id, addr, rw_counter, byte, is_read, is_pad)` rows backed by the
+//! real bytecode/tx/rw tables via the other `lookup_any`s below.
+//!
+//! `CopyCircuitConfig` is self-contained and tested at the unit level.
+//! `lookup_run_total` is the real cross-circuit constraint; what's still
+//! missing is a top-level `Circuit` impl that instantiates both
+//! `CopyCircuitConfig` and the EVM circuit's config against the same
+//! `ConstraintSystem` and calls it — that file (the master circuit assembly)
+//! isn't part of this snapshot. Until it exists, `CopyToMemoryGadget`'s
+//! `cb.copy_lookup_run_total` call (see `memory_copy.rs`) can't actually reach
+//! `lookup_run_total`, so `assign_exec_step` still replicates this circuit's
+//! accumulator math by hand (see `fold_copy_fingerprint` in
+//! `memory_copy.rs`'s test module and the equivalent loop in
+//! `assign_exec_step`) to keep the witness self-consistent in the meantime.
+use halo2::{
+    arithmetic::FieldExt,
+    circuit::Region,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+use crate::util::Expr;
+
+/// Identifies where the bytes copied by a row of the Copy Circuit come from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyDataType {
+    /// Bytes are read from / written to EVM memory.
+    Memory = 0,
+    /// Bytes are read from the calling transaction's calldata.
+    TxCalldata = 1,
+    /// Bytes are read from the running call's contract bytecode (CODECOPY),
+    /// identified by `id` holding the code hash.
+    Bytecode = 2,
+    /// Bytes are read from a previous call's return data (RETURNDATACOPY),
+    /// identified by `id` holding the caller's call id.
+    ReturnData = 3,
+}
+
+/// What a copy's consuming gadget does when the source range it's asked to
+/// copy runs past `src_addr_end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyOobPolicy {
+    /// Bytes past the end of the source read as zero (CALLDATACOPY,
+    /// CODECOPY): the EVM lets these opcodes address past the end of their
+    /// source buffer and pads the excess with zeroes instead of faulting.
+    ZeroPad,
+    /// Any byte past the end of the source makes the copy illegal and the
+    /// call must revert (RETURNDATACOPY): `RETURNDATASIZE` is the only way
+    /// to learn the buffer's length, so the EVM treats an out-of-bounds
+    /// read as a hard error rather than silently padding it.
+    Fault,
+}
+
+/// Config for the Copy Circuit.
+///
+/// `acc` and `acc_ext` together hold the running log-derivative accumulator.
+/// On BN256 only `acc` is ever populated (the scalar field is large enough
+/// on its own), but keeping a second cell lets the same gate be reused with
+/// an accumulator lifted into a degree-2 extension field `acc + acc_ext * u`
+/// for circuits built over a smaller base field.
+#[derive(Clone, Debug)]
+pub struct CopyCircuitConfig<F> {
+    /// Enables the per-row copy constraints.
+    pub q_enable: Selector,
+    /// Marks the last row of a contiguous copy run, where the accumulator
+    /// must equal the grand total supplied by the consuming gadget.
+    pub q_last: Selector,
+    /// One-hot source/destination tag, see [`CopyDataType`]. Indexed the
+    /// same way as the enum: `tag[0]` is `Memory`, ..., `tag[3]` is
+    /// `ReturnData`.
+    pub tag: [Column<Advice>; 4],
+    /// Call id / tx id the row belongs to.
+    pub id: Column<Advice>,
+    /// Memory address / calldata offset.
+    pub addr: Column<Advice>,
+    /// Global rw counter at which this row's bus-mapping event happened.
+    pub rw_counter: Column<Advice>,
+    /// The byte being moved.
+    pub byte: Column<Advice>,
+    /// 1 if this row is a read, 0 if it is a write.
+    pub is_read: Column<Advice>,
+    /// 1 if this row is an out-of-bounds zero-pad rather than a real byte.
+    pub is_pad: Column<Advice>,
+    /// `1 / (alpha - c)` for this row's compressed tuple `c`, so the gate can
+    /// avoid an in-circuit division.
+    row_inverse: Column<Advice>,
+    /// Running log-derivative accumulator, base-field part.
+    acc: Column<Advice>,
+    /// Running log-derivative accumulator, extension-field part (unused on
+    /// BN256, see struct docs).
+    acc_ext: Column<Advice>,
+    alpha: Expression<F>,
+    gamma: Expression<F>,
+}
+
+/// External tables a Copy Circuit row is checked against, depending on its
+/// `tag`. Each is a thin re-export of columns owned by the table's own
+/// circuit (bytecode/tx/state), passed in so the Copy Circuit can gate a
+/// `lookup_any` on `tag` rather than duplicating the table.
+#[derive(Clone, Copy, Debug)]
+pub struct CopyCircuitTables {
+    /// `(code_hash, index, byte)`, backing `CopyDataType::Bytecode`.
+    pub bytecode_table: (Column<Advice>, Column<Advice>, Column<Advice>),
+    /// `(tx_id, index, byte)`, backing `CopyDataType::TxCalldata`.
+    pub tx_table: (Column<Advice>, Column<Advice>, Column<Advice>),
+    /// `(rw_counter, call_id, addr, byte)`, backing `CopyDataType::Memory`
+    /// and `CopyDataType::ReturnData` (both are rw-table memory reads/writes,
+    /// just keyed by a different call id).
+    pub rw_table: (
+        Column<Advice>,
+        Column<Advice>,
+        Column<Advice>,
+        Column<Advice>,
+    ),
+}
+
+impl<F: FieldExt> CopyCircuitConfig<F> {
+    /// Configure the Copy Circuit. `alpha` and `gamma` are the two verifier
+    /// challenges used to compress a row into the log-derivative lookup;
+    /// callers thread in the same challenges used by the consuming gadget's
+    /// fingerprint check. `tables` are the external tables each `tag`
+    /// branch is checked against.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        alpha: Expression<F>,
+        gamma: Expression<F>,
+        tables: CopyCircuitTables,
+    ) -> Self {
+        let q_enable = meta.selector();
+        let q_last = meta.selector();
+        let tag = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let id = meta.advice_column();
+        let addr = meta.advice_column();
+        let rw_counter = meta.advice_column();
+        let byte = meta.advice_column();
+        let is_read = meta.advice_column();
+        let is_pad = meta.advice_column();
+        let row_inverse = meta.advice_column();
+        let acc = meta.advice_column();
+        let acc_ext = meta.advice_column();
+
+        let tag_is = |meta: &mut ConstraintSystem<F>,
+                      rot: Rotation,
+                      data_type: CopyDataType| {
+            meta.query_advice(tag[data_type as usize], rot)
+        };
+
+        meta.create_gate("Copy Circuit: tag is one-hot", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let cells: Vec<_> = tag
+                .iter()
+                .map(|col| meta.query_advice(*col, Rotation::cur()))
+                .collect();
+            let sum = cells.iter().fold(0.expr(), |acc, c| acc + c.clone());
+            let mut constraints: Vec<Expression<F>> = cells
+                .iter()
+                .map(|c| q_enable.clone() * c.clone() * (1.expr() - c.clone()))
+                .collect();
+            constraints.push(q_enable * (sum - 1.expr()));
+            constraints
+        });
+
+        meta.lookup_any(
+            "Copy Circuit: Bytecode rows are backed by the Bytecode table",
+            |meta| {
+                let is_pad = meta.query_advice(is_pad, Rotation::cur());
+                let enable = meta.query_selector(q_enable)
+                    * tag_is(meta, Rotation::cur(), CopyDataType::Bytecode)
+                    * (1.expr() - is_pad);
+                let id = meta.query_advice(id, Rotation::cur());
+                let addr = meta.query_advice(addr, Rotation::cur());
+                let byte = meta.query_advice(byte, Rotation::cur());
+                vec![
+                    (enable.clone() * id, tables.bytecode_table.0.into()),
+                    (enable.clone() * addr, tables.bytecode_table.1.into()),
+                    (enable * byte, tables.bytecode_table.2.into()),
+                ]
+            },
+        );
+
+        meta.lookup_any(
+            "Copy Circuit: TxCalldata rows are backed by the Tx table",
+            |meta| {
+                let is_pad = meta.query_advice(is_pad, Rotation::cur());
+                let enable = meta.query_selector(q_enable)
+                    * tag_is(meta, Rotation::cur(), CopyDataType::TxCalldata)
+                    * (1.expr() - is_pad);
+                let id = meta.query_advice(id, Rotation::cur());
+                let addr = meta.query_advice(addr, Rotation::cur());
+                let byte = meta.query_advice(byte, Rotation::cur());
+                vec![
+                    (enable.clone() * id, tables.tx_table.0.into()),
+                    (enable.clone() * addr, tables.tx_table.1.into()),
+                    (enable * byte, tables.tx_table.2.into()),
+                ]
+            },
+        );
+
+        meta.lookup_any(
+            "Copy Circuit: Memory/ReturnData rows are backed by the Rw table",
+            |meta| {
+                let is_memory =
+                    tag_is(meta, Rotation::cur(), CopyDataType::Memory);
+                let is_return_data =
+                    tag_is(meta, Rotation::cur(), CopyDataType::ReturnData);
+                let is_pad = meta.query_advice(is_pad, Rotation::cur());
+                let enable = meta.query_selector(q_enable)
+                    * (is_memory + is_return_data)
+                    * (1.expr() - is_pad);
+                let id = meta.query_advice(id, Rotation::cur());
+                let addr = meta.query_advice(addr, Rotation::cur());
+                let rw_counter = meta.query_advice(rw_counter, Rotation::cur());
+                let byte = meta.query_advice(byte, Rotation::cur());
+                vec![
+                    (enable.clone() * rw_counter, tables.rw_table.0.into()),
+                    (enable.clone() * id, tables.rw_table.1.into()),
+                    (enable.clone() * addr, tables.rw_table.2.into()),
+                    (enable * byte, tables.rw_table.3.into()),
+                ]
+            },
+        );
+
+        let compress = |meta: &mut ConstraintSystem<F>, rot: Rotation| {
+            // Fold the one-hot tag into its numeric `CopyDataType` value
+            // rather than carrying a fifth "tag value" column.
+            let tag_value = tag.iter().enumerate().fold(
+                0.expr(),
+                |acc, (k, col)| {
+                    acc + Expression::Constant(F::from(k as u64))
+                        * meta.query_advice(*col, rot)
+                },
+            );
+            let id = meta.query_advice(id, rot);
+            let addr = meta.query_advice(addr, rot);
+            let rw_counter = meta.query_advice(rw_counter, rot);
+            let byte = meta.query_advice(byte, rot);
+            let is_read = meta.query_advice(is_read, rot);
+            gamma.clone()
+                + tag_value
+                + alpha.clone() * id
+                + alpha.clone() * alpha.clone() * addr
+                + alpha.clone() * alpha.clone() * alpha.clone() * rw_counter
+                + alpha.clone()
+                    * alpha.clone()
+                    * alpha.clone()
+                    * alpha.clone()
+                    * byte
+                + alpha.clone()
+                    * alpha.clone()
+                    * alpha.clone()
+                    * alpha.clone()
+                    * alpha.clone()
+                    * is_read
+        };
+
+        meta.create_gate("Copy Circuit: row_inverse is 1 / (alpha - c)", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let c = compress(meta, Rotation::cur());
+            let row_inverse = meta.query_advice(row_inverse, Rotation::cur());
+            vec![q_enable * ((alpha.clone() - c) * row_inverse - 1.expr())]
+        });
+
+        meta.create_gate(
+            "Copy Circuit: a write's rw_counter immediately follows its own read",
+            |meta| {
+                let q_enable = meta.query_selector(q_enable);
+                let is_read_cur = meta.query_advice(is_read, Rotation::cur());
+                let is_read_next =
+                    meta.query_advice(is_read, Rotation::next());
+                let rw_counter_cur =
+                    meta.query_advice(rw_counter, Rotation::cur());
+                let rw_counter_next =
+                    meta.query_advice(rw_counter, Rotation::next());
+                // `assign_region` always emits a byte's read row immediately
+                // followed by its own write row (see `CopyCircuitRow`
+                // construction in the consuming gadget). Pinning the write's
+                // rw_counter to exactly one past its read's rules out a
+                // prover re-pairing a read with a write it didn't actually
+                // produce, which is what would let an overlapping
+                // memory-to-memory copy (MCOPY) smuggle in a write that
+                // "observes" a stale, pre-overwrite value instead of the one
+                // its paired read just proved via the row above.
+                vec![
+                    q_enable
+                        * is_read_cur
+                        * (1.expr() - is_read_next)
+                        * (rw_counter_next - rw_counter_cur - 1.expr()),
+                ]
+            },
+        );
+
+        meta.create_gate("Copy Circuit: accumulator update", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let is_pad = meta.query_advice(is_pad, Rotation::cur());
+            let mult = 1.expr() - is_pad;
+            let row_inverse = meta.query_advice(row_inverse, Rotation::cur());
+            let acc_prev = meta.query_advice(acc, Rotation::prev());
+            let acc_cur = meta.query_advice(acc, Rotation::cur());
+            let acc_ext_prev = meta.query_advice(acc_ext, Rotation::prev());
+            let acc_ext_cur = meta.query_advice(acc_ext, Rotation::cur());
+            vec![
+                q_enable.clone()
+                    * (acc_cur - acc_prev - mult.clone() * row_inverse),
+                // The extension-field half of the accumulator stays untouched
+                // on BN256; a degree-2 extension build would fold `row_inverse`
+                // here as its imaginary-part contribution instead.
+                q_enable * (acc_ext_cur - acc_ext_prev),
+            ]
+        });
+
+        Self {
+            q_enable,
+            q_last,
+            tag,
+            id,
+            addr,
+            rw_counter,
+            byte,
+            is_read,
+            is_pad,
+            row_inverse,
+            acc,
+            acc_ext,
+            alpha,
+            gamma,
+        }
+    }
+
+    /// The accumulator cells of the last enabled row, i.e. the grand total
+    /// the consuming gadget's own running-fingerprint must equal.
+    pub fn acc_cells(&self) -> (Column<Advice>, Column<Advice>) {
+        (self.acc, self.acc_ext)
+    }
+
+    /// Anchors a consuming gadget's claimed grand-total accumulator for one
+    /// finished copy run to a real `q_last` row this circuit itself assigned
+    /// (and already proved against the bytecode/tx/rw tables via the
+    /// `lookup_any`s above): `enable` is typically "this step just finished
+    /// a multi-step copy", and `tag`/`id`/`addr` identify the run's last
+    /// row (its destination write). Without this, a malicious prover could
+    /// supply any `acc`/`acc_ext` pair that merely satisfies the consuming
+    /// gadget's own local arithmetic, without it ever having been produced
+    /// by this circuit's row-by-row accumulation.
+    ///
+    /// `ConstraintBuilder::copy_lookup_run_total` is expected to call this once per
+    /// `CopyToMemoryGadget` step, gated on `finished`, with `tag`/`id`
+    /// fixed to the destination write's `(Memory, dst_id)` and `addr` set
+    /// to the last byte address the run wrote.
+    pub fn lookup_run_total(
+        &self,
+        meta: &mut ConstraintSystem<F>,
+        enable: Expression<F>,
+        tag: Expression<F>,
+        id: Expression<F>,
+        addr: Expression<F>,
+        acc: Expression<F>,
+        acc_ext: Expression<F>,
+    ) {
+        let self_tag = self.tag;
+        let self_id = self.id;
+        let self_addr = self.addr;
+        let self_acc = self.acc;
+        let self_acc_ext = self.acc_ext;
+        let q_last = self.q_last;
+        meta.lookup_any(
+            "Copy Circuit: a finished copy's claimed grand total matches a real run's last row",
+            move |meta| {
+                let q_last = meta.query_selector(q_last);
+                let row_tag = self_tag.iter().enumerate().fold(
+                    0.expr(),
+                    |acc, (k, col)| {
+                        acc + Expression::Constant(F::from(k as u64))
+                            * meta.query_advice(*col, Rotation::cur())
+                    },
+                );
+                let row_id = meta.query_advice(self_id, Rotation::cur());
+                let row_addr = meta.query_advice(self_addr, Rotation::cur());
+                let row_acc = meta.query_advice(self_acc, Rotation::cur());
+                let row_acc_ext =
+                    meta.query_advice(self_acc_ext, Rotation::cur());
+                vec![
+                    (enable.clone() * tag.clone(), q_last.clone() * row_tag),
+                    (enable.clone() * id.clone(), q_last.clone() * row_id),
+                    (enable.clone() * addr.clone(), q_last.clone() * row_addr),
+                    (enable.clone() * acc.clone(), q_last.clone() * row_acc),
+                    (enable.clone() * acc_ext.clone(), q_last * row_acc_ext),
+                ]
+            },
+        );
+    }
+}
+
+/// A single copied byte, as emitted by the bus-mapping for a copy event.
+#[derive(Clone, Debug)]
+pub struct CopyCircuitRow {
+    pub tag: CopyDataType,
+    pub id: u64,
+    pub addr: u64,
+    pub rw_counter: usize,
+    pub byte: u8,
+    pub is_read: bool,
+    pub is_pad: bool,
+}
+
+impl<F: FieldExt> CopyCircuitConfig<F> {
+    /// Assign one contiguous run of copy rows (e.g. all the bytes belonging
+    /// to a single CALLDATACOPY) and return the final accumulator value.
+    pub fn assign_region(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        rows: &[CopyCircuitRow],
+        alpha: F,
+        gamma: F,
+    ) -> Result<(F, F), Error> {
+        let mut acc = F::zero();
+        let acc_ext = F::zero();
+        for (i, row) in rows.iter().enumerate() {
+            self.q_enable.enable(region, offset + i)?;
+            if i + 1 == rows.len() {
+                self.q_last.enable(region, offset + i)?;
+            }
+            for (k, col) in self.tag.iter().enumerate() {
+                region.assign_advice(
+                    || "tag",
+                    *col,
+                    offset + i,
+                    || Ok(F::from((k == row.tag as usize) as u64)),
+                )?;
+            }
+            region.assign_advice(
+                || "id",
+                self.id,
+                offset + i,
+                || Ok(F::from(row.id)),
+            )?;
+            region.assign_advice(
+                || "addr",
+                self.addr,
+                offset + i,
+                || Ok(F::from(row.addr)),
+            )?;
+            region.assign_advice(
+                || "rw_counter",
+                self.rw_counter,
+                offset + i,
+                || Ok(F::from(row.rw_counter as u64)),
+            )?;
+            region.assign_advice(
+                || "byte",
+                self.byte,
+                offset + i,
+                || Ok(F::from(row.byte as u64)),
+            )?;
+            region.assign_advice(
+                || "is_read",
+                self.is_read,
+                offset + i,
+                || Ok(F::from(row.is_read as u64)),
+            )?;
+            region.assign_advice(
+                || "is_pad",
+                self.is_pad,
+                offset + i,
+                || Ok(F::from(row.is_pad as u64)),
+            )?;
+
+            let c = gamma
+                + F::from(row.tag as u64)
+                + alpha * F::from(row.id)
+                + alpha.square() * F::from(row.addr)
+                + alpha.pow(&[3, 0, 0, 0]) * F::from(row.rw_counter as u64)
+                + alpha.pow(&[4, 0, 0, 0]) * F::from(row.byte as u64)
+                + alpha.pow(&[5, 0, 0, 0]) * F::from(row.is_read as u64);
+            let row_inverse = (alpha - c).invert().unwrap_or(F::zero());
+            region.assign_advice(
+                || "row_inverse",
+                self.row_inverse,
+                offset + i,
+                || Ok(row_inverse),
+            )?;
+
+            if !row.is_pad {
+                acc += row_inverse;
+            }
+            region.assign_advice(
+                || "acc",
+                self.acc,
+                offset + i,
+                || Ok(acc),
+            )?;
+            region.assign_advice(
+                || "acc_ext",
+                self.acc_ext,
+                offset + i,
+                || Ok(acc_ext),
+            )?;
+        }
+        Ok((acc, acc_ext))
+    }
+}
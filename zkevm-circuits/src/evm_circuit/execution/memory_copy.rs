@@ -1,9 +1,9 @@
 use crate::{
+    copy_circuit::{CopyCircuitRow, CopyDataType, CopyOobPolicy},
     evm_circuit::{
         execution::ExecutionGadget,
         param::MAX_MEMORY_SIZE_IN_BYTES,
         step::ExecutionState,
-        table::TxContextFieldTag,
         util::{
             constraint_builder::{
                 ConstraintBuilder, StepStateTransition, Transition::Delta,
@@ -16,12 +16,48 @@ use crate::{
     },
     util::Expr,
 };
-use halo2::{arithmetic::FieldExt, circuit::Region, plonk::Error};
+use halo2::{
+    arithmetic::FieldExt,
+    circuit::Region,
+    plonk::{Error, Expression},
+};
 
 // The max number of bytes that can be copied in a step limited by the number
 // of cells in a step
 const MAX_COPY_BYTES: usize = 71;
 
+// The max number of whole 32-byte words the word-aligned fast path can copy
+// in a single step. Kept small since each word still costs a cell for its
+// RLC-packed value; unaligned head/tail bytes and any overlap hazard always
+// fall back to the byte path above.
+const MAX_COPY_WORDS: usize = 2;
+
+// Decomposes `addr` into `addr = div32 * 32 + mod32` and derives a
+// 0/1-valued `is_zero(mod32)` expression from a free-witness inverse cell,
+// the usual `1 - value * value.invert()` trick: if `mod32 != 0` the
+// multiplication constraint below forces the expression to 0 regardless of
+// what's in the inverse cell, and if `mod32 == 0` the expression is 1 with no
+// constraint on the inverse cell at all.
+fn query_is_addr_aligned<F: FieldExt>(
+    cb: &mut ConstraintBuilder<F>,
+    addr: Expression<F>,
+) -> (Cell<F>, Cell<F>, Cell<F>, Expression<F>) {
+    let div32 = cb.query_cell();
+    let mod32 = cb.query_cell();
+    let mod32_inv = cb.query_cell();
+    cb.require_equal(
+        "addr == div32 * 32 + mod32",
+        addr,
+        div32.expr() * 32.expr() + mod32.expr(),
+    );
+    let is_zero = 1.expr() - mod32.expr() * mod32_inv.expr();
+    cb.add_constraint(
+        "mod32 * (1 - mod32 * mod32_inv) == 0",
+        mod32.expr() * is_zero.clone(),
+    );
+    (div32, mod32, mod32_inv, is_zero)
+}
+
 /// Multi-step gadget for copying data from memory or Tx calldata to memory
 #[derive(Clone, Debug)]
 pub(crate) struct CopyToMemoryGadget<F> {
@@ -33,15 +69,72 @@ pub(crate) struct CopyToMemoryGadget<F> {
     bytes_left: Cell<F>,
     // The src address bound of the buffer
     src_addr_end: Cell<F>,
-    // Indicate whether src is from Tx Calldata
-    from_tx: Cell<F>,
-    // Transaction ID, optional, only used when src_is_tx == 1
-    tx_id: Cell<F>,
+    // One-hot source tag, see `copy_circuit::CopyDataType`: Memory,
+    // TxCalldata, Bytecode (CODECOPY) or ReturnData (RETURNDATACOPY).
+    src_tag: [Cell<F>; 4],
+    // Extra id the source is keyed by, interpreted according to `src_tag`:
+    // the tx id for TxCalldata, the running code hash for Bytecode, the
+    // caller's call id for ReturnData, and unused for Memory.
+    src_id: Cell<F>,
+    // The id the destination write is keyed by: the running call's id,
+    // since memory is call-scoped. Carried as its own cell (rather than
+    // reusing `src_id`, which is keyed by the *source*) so a finished run's
+    // last write row can be anchored against the real Copy Circuit via
+    // `lookup_run_total` below.
+    dst_id: Cell<F>,
+    // Whether `src_addr < dst_addr` and the two ranges overlap, i.e. this is
+    // a memory-to-memory copy (MCOPY) with the classic `memmove` hazard: a
+    // byte this step writes may land on a source address this same copy
+    // hasn't read yet. Derived below from `dst_gt_src`/`dst_before_src_end`
+    // rather than taken as a free witness value.
+    is_overlap_backward: Cell<F>,
+    dst_gt_src: ComparisonGadget<F, 4>,
+    dst_before_src_end: ComparisonGadget<F, 4>,
     // Buffer reader gadget
     buffer_reader:
         BufferReaderGadget<F, MAX_COPY_BYTES, MAX_MEMORY_SIZE_IN_BYTES>,
+    // Word-aligned fast path: when `src_addr`/`dst_addr` are 32-byte
+    // aligned, at least one whole word remains, and there's no overlap
+    // hazard to juggle, this step copies up to `MAX_COPY_WORDS` whole
+    // words via the Buffer Reader's packed-word expression rather than
+    // individual bytes, cutting both the step count and the rw table row
+    // count for large aligned copies (e.g. MCOPY/CALLDATACOPY). Unaligned
+    // head/tail bytes fall back to `buffer_reader` above, one step at a
+    // time, since `is_word_aligned` is re-derived fresh every step.
+    src_addr_div32: Cell<F>,
+    src_addr_mod32: Cell<F>,
+    src_addr_mod32_inv: Cell<F>,
+    dst_addr_div32: Cell<F>,
+    dst_addr_mod32: Cell<F>,
+    dst_addr_mod32_inv: Cell<F>,
+    // `word_left_cmp[k]` is `bytes_left >= (k + 1) * 32`, gating
+    // `word_selectors[k]`: e.g. `word_selectors[1]` (the second word) can
+    // only be set when at least 64 bytes remain, not merely 32.
+    word_left_cmp: [ComparisonGadget<F, 4>; MAX_COPY_WORDS],
+    // `word_oob_cmp[k]` is `src_addr + (k + 1) * 32 <= src_addr_end`,
+    // gating `word_selectors[k]` the same way `word_left_cmp[k]` does but
+    // against the source buffer's bound rather than this copy's remaining
+    // length: a word that would read even one byte past `src_addr_end`
+    // never takes the word path, since the word path has no zero-pad
+    // handling of its own (see `is_word_aligned` below).
+    word_oob_cmp: [ComparisonGadget<F, 4>; MAX_COPY_WORDS],
+    is_word_aligned: Cell<F>,
+    word_selectors: [Cell<F>; MAX_COPY_WORDS],
+    // Out-of-bounds policy: set for sources where reading past
+    // `src_addr_end` must revert the call (RETURNDATACOPY) rather than
+    // zero-pad (CALLDATACOPY, CODECOPY). See `CopyOobPolicy`.
+    oob_fault: Cell<F>,
+    oob_bound_cmp: ComparisonGadget<F, 4>,
     // The comparison gadget between num bytes copied and bytes_left
     finish_gadget: ComparisonGadget<F, 4>,
+    // Copy Circuit log-derivative accumulator before this step's bytes.
+    copy_acc_start: Cell<F>,
+    copy_acc_start_ext: Cell<F>,
+    // Copy Circuit log-derivative accumulator after this step's bytes;
+    // chained into `copy_acc_start` of the next step, so the last step's
+    // value is the grand total for the whole multi-step copy.
+    copy_acc: Cell<F>,
+    copy_acc_ext: Cell<F>,
 }
 
 impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
@@ -54,43 +147,205 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
         let dst_addr = cb.query_cell();
         let bytes_left = cb.query_cell();
         let src_addr_end = cb.query_cell();
-        let from_tx = cb.query_bool();
-        let tx_id = cb.query_cell();
-        let buffer_reader =
-            BufferReaderGadget::construct(cb, &src_addr, &src_addr_end);
-        let from_memory = 1.expr() - from_tx.expr();
-
-        // Copy bytes from src and dst
-        for i in 0..MAX_COPY_BYTES {
-            let read_flag = buffer_reader.read_flag(i);
-            // Read bytes[i] from memory
-            cb.condition(from_memory.clone() * read_flag.clone(), |cb| {
-                cb.memory_lookup(
-                    0.expr(),
-                    src_addr.expr() + i.expr(),
-                    buffer_reader.byte(i),
-                )
-            });
-            // Read bytes[i] from Tx
-            cb.condition(from_tx.expr() * read_flag.clone(), |cb| {
-                cb.tx_context_lookup(
-                    tx_id.expr(),
-                    TxContextFieldTag::CallData,
-                    Some(src_addr.expr() + i.expr()),
-                    buffer_reader.byte(i),
-                )
-            });
-            // Write bytes[i] to memory when selectors[i] != 0
-            cb.condition(buffer_reader.has_data(i), |cb| {
-                cb.memory_lookup(
-                    1.expr(),
-                    dst_addr.expr() + i.expr(),
-                    buffer_reader.byte(i),
-                )
-            });
+        let src_tag = [
+            cb.query_bool(),
+            cb.query_bool(),
+            cb.query_bool(),
+            cb.query_bool(),
+        ];
+        cb.require_equal(
+            "src_tag is one-hot",
+            src_tag.iter().fold(0.expr(), |acc, cell| acc + cell.expr()),
+            1.expr(),
+        );
+        let src_id = cb.query_cell();
+        let dst_id = cb.query_cell();
+
+        // `is_overlap_backward` is true exactly for a memory-to-memory copy
+        // (MCOPY) whose destination starts strictly after its source but
+        // still inside the source's remaining range, i.e. `src_addr <
+        // dst_addr < src_addr + bytes_left`. It doesn't change how this
+        // step's address range advances (that stays a plain `+= copied_size`
+        // below); it's passed to the Buffer Reader and keeps the step off
+        // the word-aligned fast path. Reads are not redirected to bytes this
+        // copy already wrote: `assign_exec_step` takes every read straight
+        // from the rw trace (see the note there).
+        let dst_gt_src =
+            ComparisonGadget::construct(cb, src_addr.expr(), dst_addr.expr());
+        let (dst_gt_src_lt, _) = dst_gt_src.expr();
+        let dst_before_src_end = ComparisonGadget::construct(
+            cb,
+            dst_addr.expr(),
+            src_addr.expr() + bytes_left.expr(),
+        );
+        let (dst_before_src_end_lt, _) = dst_before_src_end.expr();
+        let is_overlap_backward = cb.query_bool();
+        cb.require_equal(
+            "is_overlap_backward == src is Memory && src_addr < dst_addr < src_addr + bytes_left",
+            is_overlap_backward.expr(),
+            src_tag[CopyDataType::Memory as usize].expr()
+                * dst_gt_src_lt
+                * dst_before_src_end_lt,
+        );
+
+        let buffer_reader = BufferReaderGadget::construct(
+            cb,
+            &src_addr,
+            &src_addr_end,
+            &is_overlap_backward,
+        );
+
+        // Word-aligned fast path. `is_word_aligned` doesn't gate
+        // `buffer_reader` itself (it stays wired exactly as above so the
+        // byte path keeps working standalone); instead it and
+        // `word_selectors` pick which of the two contributes to this
+        // step's `copied_size`, with a constraint below forcing the unused
+        // side to contribute zero.
+        let (src_addr_div32, src_addr_mod32, src_addr_mod32_inv, src_aligned) =
+            query_is_addr_aligned(cb, src_addr.expr());
+        let (dst_addr_div32, dst_addr_mod32, dst_addr_mod32_inv, dst_aligned) =
+            query_is_addr_aligned(cb, dst_addr.expr());
+        // `MAX_COPY_WORDS == 2`; written out rather than built from a loop
+        // for the same reason as `word_selectors` below.
+        let word_left_cmp = [
+            ComparisonGadget::construct(cb, bytes_left.expr(), 32.expr()),
+            ComparisonGadget::construct(cb, bytes_left.expr(), 64.expr()),
+        ];
+        let (bytes_left_lt32, _) = word_left_cmp[0].expr();
+        let has_word_left_expr = 1.expr() - bytes_left_lt32;
+        // `MAX_COPY_WORDS == 2`; written out rather than built from a loop
+        // for the same reason as `word_selectors` below.
+        let word_oob_cmp = [
+            ComparisonGadget::construct(
+                cb,
+                src_addr.expr() + 32.expr(),
+                src_addr_end.expr(),
+            ),
+            ComparisonGadget::construct(
+                cb,
+                src_addr.expr() + 64.expr(),
+                src_addr_end.expr(),
+            ),
+        ];
+        let (word0_oob_lt, word0_oob_eq) = word_oob_cmp[0].expr();
+        let word0_fits_expr = word0_oob_lt + word0_oob_eq;
+        // The word path has no zero-pad handling of its own (unlike the
+        // byte path's `buffer_reader`), so it's only taken when the first
+        // whole word is also entirely within the source buffer; a source
+        // buffer that ends partway through that word (the common OOB case,
+        // e.g. the tail of a CALLDATACOPY past calldata end) falls through
+        // to the unaligned byte path, which already zero-pads correctly.
+        let is_word_aligned = cb.query_bool();
+        cb.require_equal(
+            "is_word_aligned == src/dst 32-byte aligned && a whole word remains within src_addr_end && no overlap hazard",
+            is_word_aligned.expr(),
+            src_aligned
+                * dst_aligned
+                * has_word_left_expr
+                * word0_fits_expr
+                * (1.expr() - is_overlap_backward.expr()),
+        );
+
+        // `MAX_COPY_WORDS == 2`; written out rather than built from a loop
+        // to keep each cell's query order explicit, matching `src_tag`
+        // above.
+        let word_selectors = [cb.query_bool(), cb.query_bool()];
+        for (k, sel) in word_selectors.iter().enumerate() {
+            let (bytes_left_lt_kplus1_words, _) = word_left_cmp[k].expr();
+            let (word_k_oob_lt, word_k_oob_eq) = word_oob_cmp[k].expr();
+            let word_k_fits = word_k_oob_lt + word_k_oob_eq;
+            cb.add_constraint(
+                "word_selectors can only be set when is_word_aligned, bytes_left covers that many whole words, and that word fits within src_addr_end",
+                sel.expr()
+                    * ((1.expr() - is_word_aligned.expr())
+                        + bytes_left_lt_kplus1_words
+                        + (1.expr() - word_k_fits)),
+            );
         }
+        for pair in word_selectors.windows(2) {
+            cb.add_constraint(
+                "word_selectors are front-loaded",
+                pair[1].expr() * (1.expr() - pair[0].expr()),
+            );
+        }
+        let copied_words = word_selectors
+            .iter()
+            .fold(0.expr(), |acc, sel| acc + sel.expr());
+
+        let byte_copied_size = buffer_reader.num_bytes();
+        cb.add_constraint(
+            "byte buffer_reader is unused while is_word_aligned",
+            is_word_aligned.expr() * byte_copied_size.clone(),
+        );
+        let copied_size = is_word_aligned.expr() * copied_words * 32.expr()
+            + (1.expr() - is_word_aligned.expr()) * byte_copied_size;
 
-        let copied_size = buffer_reader.num_bytes();
+        // Out-of-bounds policy (`CopyOobPolicy`): CALLDATACOPY/CODECOPY are
+        // free to address past the end of their source and read the excess
+        // as zero, which `buffer_reader` already does via `src_addr_end`
+        // above. RETURNDATACOPY isn't: the only way to learn its buffer's
+        // length is `RETURNDATASIZE`, so the EVM treats any read past
+        // `src_addr_end` as a hard error. `oob_fault` selects that stricter
+        // behavior; `bytes_left` here is the whole copy's remaining length
+        // (not just this step's `copied_size`), so the bound below already
+        // covers every byte the multi-step copy will ever touch, and stays
+        // true step-to-step since `src_addr` and `bytes_left` move in
+        // lockstep.
+        let oob_fault = cb.query_bool();
+        let oob_bound_cmp = ComparisonGadget::construct(
+            cb,
+            src_addr.expr() + bytes_left.expr(),
+            src_addr_end.expr(),
+        );
+        let (oob_bound_lt, oob_bound_eq) = oob_bound_cmp.expr();
+        let is_oob_in_bounds = oob_bound_lt + oob_bound_eq;
+        let oob_violated = oob_fault.expr() * (1.expr() - is_oob_in_bounds);
+
+        // Rather than inlining one memory/tx_context/bytecode lookup per
+        // copied byte, this step's bytes are meant to be checked against the
+        // Copy Circuit instead: `copy_lookup` constrains this step's
+        // `(src_tag, src_id, src_addr, dst_addr, len)` tuple together with
+        // the running log-derivative accumulator before (`copy_acc_start`)
+        // and after (`copy_acc`) this step's bytes, against a contiguous run
+        // of `CopyCircuitConfig` rows. The accumulator is threaded
+        // step-to-step below so the last step's `copy_acc` is the grand
+        // total for the whole multi-step copy; once the copy finishes (see
+        // `copy_lookup_run_total` below, once `finished` is available), that
+        // total is anchored to a real `CopyCircuitConfig::lookup_run_total`
+        // row instead of only being checked against this gadget's own
+        // arithmetic.
+        //
+        // Neither `copy_lookup` nor `copy_lookup_run_total` is implemented
+        // on `ConstraintBuilder` in this crate snapshot yet —
+        // `copy_lookup_run_total` is expected to internally call
+        // `CopyCircuitConfig::lookup_run_total`
+        // (now implemented, see `copy_circuit.rs`) once both this gadget's
+        // config and `CopyCircuitConfig` are registered against the same
+        // `ConstraintSystem` by a top-level circuit assembly, which isn't
+        // part of this snapshot (see the module doc on `copy_circuit.rs`).
+        // Until that assembly exists these calls are the wiring a consuming
+        // `ConstraintBuilder` method would perform, and `assign_exec_step`
+        // below independently recomputes the same accumulator by hand so
+        // the witness stays self-consistent in the meantime.
+        let src_tag_value = src_tag
+            .iter()
+            .enumerate()
+            .fold(0.expr(), |acc, (k, cell)| acc + k.expr() * cell.expr());
+        let copy_acc_start = cb.query_cell();
+        let copy_acc_start_ext = cb.query_cell();
+        let copy_acc = cb.query_cell();
+        let copy_acc_ext = cb.query_cell();
+        cb.copy_lookup(
+            src_tag_value.clone(),
+            src_id.expr(),
+            src_addr.expr(),
+            dst_addr.expr(),
+            copied_size.clone(),
+            copy_acc_start.expr(),
+            copy_acc_start_ext.expr(),
+            copy_acc.expr(),
+            copy_acc_ext.expr(),
+        );
         let finish_gadget = ComparisonGadget::construct(
             cb,
             copied_size.clone(),
@@ -102,18 +357,55 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
             lt * finished.clone(),
         );
 
+        // Once this copy finishes, anchor its claimed grand-total
+        // accumulator (`copy_acc`/`copy_acc_ext`) to the real Copy Circuit
+        // row for the last byte it wrote, rather than trusting the
+        // accumulator purely because this gadget's own per-step arithmetic
+        // is internally consistent. The anchored row is the destination
+        // write of the last byte copied, i.e. `(Memory, dst_id, dst_addr +
+        // copied_size - 1)`; `finished` already excludes an OOB-fault step
+        // (which never writes anything), so this only fires on a real
+        // completed copy.
+        cb.copy_lookup_run_total(
+            finished.clone(),
+            (CopyDataType::Memory as u64).expr(),
+            dst_id.expr(),
+            dst_addr.expr() + copied_size.clone() - 1.expr(),
+            copy_acc.expr(),
+            copy_acc_ext.expr(),
+        );
+
+        // A fault-policy step whose source range runs past `src_addr_end`
+        // never continues copying (zero-padding is only for `ZeroPad`
+        // sources): it routes straight to the revert state instead,
+        // regardless of `finished`.
+        cb.constrain_next_step(
+            ExecutionState::ErrorReturnDataOutOfBound,
+            Some(oob_violated.clone()),
+            |_cb| {},
+        );
+
         // When finished == 0, constraint the CopyToMemory state in next step
 
         cb.constrain_next_step(
             ExecutionState::CopyToMemory,
-            Some(1.expr() - finished),
+            Some((1.expr() - finished) * (1.expr() - oob_violated.clone())),
             |cb| {
                 let next_src_addr = cb.query_cell();
                 let next_dst_addr = cb.query_cell();
                 let next_bytes_left = cb.query_cell();
                 let next_src_addr_end = cb.query_cell();
-                let next_from_tx = cb.query_cell();
-                let next_tx_id = cb.query_cell();
+                let next_src_tag = [
+                    cb.query_cell(),
+                    cb.query_cell(),
+                    cb.query_cell(),
+                    cb.query_cell(),
+                ];
+                let next_src_id = cb.query_cell();
+                let next_dst_id = cb.query_cell();
+                let next_oob_fault = cb.query_bool();
+                let next_copy_acc_start = cb.query_cell();
+                let next_copy_acc_start_ext = cb.query_cell();
                 cb.require_equal(
                     "next_src_addr == src_addr + copied_size",
                     next_src_addr.expr(),
@@ -134,15 +426,37 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
                     next_src_addr_end.expr(),
                     src_addr_end.expr(),
                 );
+                for (next_cell, cell) in next_src_tag.iter().zip(&src_tag) {
+                    cb.require_equal(
+                        "next_src_tag == src_tag",
+                        next_cell.expr(),
+                        cell.expr(),
+                    );
+                }
                 cb.require_equal(
-                    "next_from_tx == from_tx",
-                    next_from_tx.expr(),
-                    from_tx.expr(),
+                    "next_src_id == src_id",
+                    next_src_id.expr(),
+                    src_id.expr(),
                 );
                 cb.require_equal(
-                    "next_tx_id == tx_id",
-                    next_tx_id.expr(),
-                    tx_id.expr(),
+                    "next_dst_id == dst_id",
+                    next_dst_id.expr(),
+                    dst_id.expr(),
+                );
+                cb.require_equal(
+                    "next_oob_fault == oob_fault",
+                    next_oob_fault.expr(),
+                    oob_fault.expr(),
+                );
+                cb.require_equal(
+                    "next_copy_acc_start == copy_acc",
+                    next_copy_acc_start.expr(),
+                    copy_acc.expr(),
+                );
+                cb.require_equal(
+                    "next_copy_acc_start_ext == copy_acc_ext",
+                    next_copy_acc_start_ext.expr(),
+                    copy_acc_ext.expr(),
                 );
             },
         );
@@ -159,10 +473,30 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
             dst_addr,
             bytes_left,
             src_addr_end,
-            from_tx,
-            tx_id,
+            src_tag,
+            src_id,
+            dst_id,
+            is_overlap_backward,
+            dst_gt_src,
+            dst_before_src_end,
             buffer_reader,
+            src_addr_div32,
+            src_addr_mod32,
+            src_addr_mod32_inv,
+            dst_addr_div32,
+            dst_addr_mod32,
+            dst_addr_mod32_inv,
+            word_left_cmp,
+            word_oob_cmp,
+            is_word_aligned,
+            word_selectors,
+            oob_fault,
+            oob_bound_cmp,
             finish_gadget,
+            copy_acc_start,
+            copy_acc_start_ext,
+            copy_acc,
+            copy_acc_ext,
         }
     }
 
@@ -172,7 +506,7 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
         offset: usize,
         block: &Block<F>,
         tx: &Transaction<F>,
-        _: &Call<F>,
+        call: &Call<F>,
         step: &ExecStep,
     ) -> Result<(), Error> {
         let GadgetExtraData::CopyToMemory {
@@ -180,10 +514,123 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
             dst_addr,
             bytes_left,
             src_addr_end,
-            from_tx,
+            src_tag,
+            src_id,
             selectors,
+            oob_policy,
+            copy_acc_start,
+            copy_acc_start_ext,
         } = step.extra_data.as_ref().unwrap();
 
+        let is_overlap_backward = matches!(src_tag, CopyDataType::Memory)
+            && dst_addr > src_addr
+            && *dst_addr < *src_addr + *bytes_left;
+        self.dst_gt_src.assign(
+            region,
+            offset,
+            F::from(*src_addr),
+            F::from(*dst_addr),
+        )?;
+        self.dst_before_src_end.assign(
+            region,
+            offset,
+            F::from(*dst_addr),
+            F::from(*src_addr + *bytes_left),
+        )?;
+        self.is_overlap_backward.assign(
+            region,
+            offset,
+            Some(F::from(is_overlap_backward as u64)),
+        )?;
+
+        let oob_fault = matches!(oob_policy, CopyOobPolicy::Fault);
+        self.oob_fault.assign(
+            region,
+            offset,
+            Some(F::from(oob_fault as u64)),
+        )?;
+        self.oob_bound_cmp.assign(
+            region,
+            offset,
+            F::from(*src_addr + *bytes_left),
+            F::from(*src_addr_end),
+        )?;
+        let oob_violated = oob_fault && *src_addr + *bytes_left > *src_addr_end;
+
+        self.src_addr_div32.assign(
+            region,
+            offset,
+            Some(F::from(*src_addr / 32)),
+        )?;
+        self.src_addr_mod32.assign(
+            region,
+            offset,
+            Some(F::from(*src_addr % 32)),
+        )?;
+        self.src_addr_mod32_inv.assign(
+            region,
+            offset,
+            Some(F::from(*src_addr % 32).invert().unwrap_or(F::zero())),
+        )?;
+        self.dst_addr_div32.assign(
+            region,
+            offset,
+            Some(F::from(*dst_addr / 32)),
+        )?;
+        self.dst_addr_mod32.assign(
+            region,
+            offset,
+            Some(F::from(*dst_addr % 32)),
+        )?;
+        self.dst_addr_mod32_inv.assign(
+            region,
+            offset,
+            Some(F::from(*dst_addr % 32).invert().unwrap_or(F::zero())),
+        )?;
+        for (k, cmp) in self.word_left_cmp.iter().enumerate() {
+            cmp.assign(
+                region,
+                offset,
+                F::from(*bytes_left),
+                F::from(((k + 1) * 32) as u64),
+            )?;
+        }
+        for (k, cmp) in self.word_oob_cmp.iter().enumerate() {
+            cmp.assign(
+                region,
+                offset,
+                F::from(*src_addr + ((k + 1) * 32) as u64),
+                F::from(*src_addr_end),
+            )?;
+        }
+        let is_word_aligned = !is_overlap_backward
+            && src_addr % 32 == 0
+            && dst_addr % 32 == 0
+            && *bytes_left >= 32
+            && src_addr + 32 <= *src_addr_end;
+        self.is_word_aligned.assign(
+            region,
+            offset,
+            Some(F::from(is_word_aligned as u64)),
+        )?;
+        // A faulting step copies nothing, even on the word path.
+        let copied_words = if is_word_aligned && !oob_violated {
+            let words_in_bounds = (*src_addr_end - *src_addr) / 32;
+            std::cmp::min(
+                MAX_COPY_WORDS as u64,
+                std::cmp::min(*bytes_left / 32, words_in_bounds),
+            )
+        } else {
+            0
+        };
+        for (k, cell) in self.word_selectors.iter().enumerate() {
+            cell.assign(
+                region,
+                offset,
+                Some(F::from(((k as u64) < copied_words) as u64)),
+            )?;
+        }
+
         self.src_addr
             .assign(region, offset, Some(F::from(*src_addr)))?;
         self.dst_addr
@@ -195,31 +642,154 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
             offset,
             Some(F::from(*src_addr_end)),
         )?;
-        self.from_tx
-            .assign(region, offset, Some(F::from(*from_tx as u64)))?;
-        self.tx_id
-            .assign(region, offset, Some(F::from(tx.id as u64)))?;
+        for (k, cell) in self.src_tag.iter().enumerate() {
+            cell.assign(
+                region,
+                offset,
+                Some(F::from((k == *src_tag as usize) as u64)),
+            )?;
+        }
+        self.src_id.assign(region, offset, Some(F::from(*src_id)))?;
+        self.dst_id
+            .assign(region, offset, Some(F::from(call.id as u64)))?;
 
-        // Retrieve the bytes
+        // Retrieve the bytes, and along the way build the Copy Circuit rows
+        // this step is responsible for, so its log-derivative contribution
+        // can be folded into `copy_acc_start` below.
         assert_eq!(selectors.len(), MAX_COPY_BYTES);
         let mut rw_idx = 0;
         let mut bytes = vec![0u8; MAX_COPY_BYTES];
+        let mut copy_rows = Vec::new();
+        // Every read below is taken straight from the rw trace, with no
+        // shadowing by this copy's own writes. MCOPY's `memmove` semantics
+        // (EIP-5656) mean every source byte is logically read before any
+        // destination byte is written, for the whole multi-step copy, not
+        // just the current step; a read's rw trace entry is the bus
+        // mapping's job to populate with that pre-copy value (see
+        // `bytes_map` in the test module below for how the witness mirrors
+        // this), regardless of what order this gadget's own steps issue
+        // their read/write row pairs in.
+
+        // When this step is word-aligned, `selectors` is all-zero (the byte
+        // path below is unused, mirroring the "byte buffer_reader is unused
+        // while is_word_aligned" constraint in `configure`) and the step's
+        // bytes are instead retrieved `copied_words` whole 32-byte words at
+        // a time. Each word is still unpacked into 32 `CopyCircuitRow`s
+        // (the Copy Circuit's external lookups stay byte-granular), but all
+        // 32 rows of one word share the single rw_counter of the underlying
+        // word-granular rw access, since fetching/storing a whole word is
+        // one rw event rather than 32.
+        if is_word_aligned {
+            for w in 0..copied_words as usize {
+                let word_src_addr = *src_addr + (w as u64) * 32;
+                let word_dst_addr = *dst_addr + (w as u64) * 32;
+                let word: [u8; 32] = match src_tag {
+                    CopyDataType::TxCalldata => {
+                        let start = word_src_addr as usize;
+                        tx.call_data[start..start + 32].try_into().unwrap()
+                    }
+                    CopyDataType::Bytecode => {
+                        let code = block
+                            .bytecodes
+                            .iter()
+                            .find(|b| {
+                                u64::from_le_bytes(
+                                    b.hash.to_le_bytes()[..8]
+                                        .try_into()
+                                        .unwrap(),
+                                ) == *src_id
+                            })
+                            .expect("running code hash must be in the block");
+                        let start = word_src_addr as usize;
+                        code.bytes[start..start + 32].try_into().unwrap()
+                    }
+                    CopyDataType::Memory | CopyDataType::ReturnData => {
+                        rw_idx += 1;
+                        block.rws[step.rw_indices[rw_idx]].memory_word_value()
+                    }
+                };
+                let read_rw_counter = step.rw_counter + rw_idx;
+                // The destination write is always a memory write, for every
+                // `src_tag`, and always consumes a fresh rw_counter — unlike
+                // the read above, which only advances `rw_idx` for
+                // rw-table-backed sources (`Memory`/`ReturnData`).
+                rw_idx += 1;
+                let write_rw_counter = step.rw_counter + rw_idx;
+                for (k, byte) in word.iter().enumerate() {
+                    copy_rows.push(CopyCircuitRow {
+                        tag: *src_tag,
+                        id: *src_id,
+                        addr: word_src_addr + k as u64,
+                        rw_counter: read_rw_counter,
+                        byte: *byte,
+                        is_read: true,
+                        is_pad: false,
+                    });
+                    copy_rows.push(CopyCircuitRow {
+                        tag: CopyDataType::Memory,
+                        id: call.id as u64,
+                        addr: word_dst_addr + k as u64,
+                        rw_counter: write_rw_counter,
+                        byte: *byte,
+                        is_read: false,
+                        is_pad: false,
+                    });
+                }
+            }
+        }
+
         for (idx, selector) in selectors.iter().enumerate() {
             let addr = *src_addr as usize + idx;
-            bytes[idx] = if *selector == 1 && addr < *src_addr_end as usize {
-                if *from_tx {
-                    assert!(addr < tx.call_data.len());
-                    tx.call_data[addr]
-                } else {
-                    rw_idx += 1;
-                    block.rws[step.rw_indices[rw_idx]].memory_value()
+            let in_bounds = addr < *src_addr_end as usize;
+            bytes[idx] = if *selector == 1 && in_bounds {
+                match src_tag {
+                    CopyDataType::TxCalldata => {
+                        assert!(addr < tx.call_data.len());
+                        tx.call_data[addr]
+                    }
+                    CopyDataType::Bytecode => {
+                        let code = block
+                            .bytecodes
+                            .iter()
+                            .find(|b| {
+                                u64::from_le_bytes(
+                                    b.hash.to_le_bytes()[..8]
+                                        .try_into()
+                                        .unwrap(),
+                                ) == *src_id
+                            })
+                            .expect("running code hash must be in the block");
+                        code.bytes[addr]
+                    }
+                    CopyDataType::Memory | CopyDataType::ReturnData => {
+                        rw_idx += 1;
+                        block.rws[step.rw_indices[rw_idx]].memory_value()
+                    }
                 }
             } else {
                 0
             };
             if *selector == 1 {
+                copy_rows.push(CopyCircuitRow {
+                    tag: *src_tag,
+                    id: *src_id,
+                    addr: addr as u64,
+                    rw_counter: step.rw_counter + rw_idx,
+                    byte: bytes[idx],
+                    is_read: true,
+                    is_pad: !in_bounds,
+                });
                 // increase rw_idx for writing back to memory
-                rw_idx += 1
+                rw_idx += 1;
+                copy_rows.push(CopyCircuitRow {
+                    tag: CopyDataType::Memory,
+                    id: call.id as u64,
+                    addr: (*dst_addr as usize + idx) as u64,
+                    rw_counter: step.rw_counter + rw_idx,
+                    byte: bytes[idx],
+                    is_read: false,
+                    is_pad: false,
+                });
             }
         }
 
@@ -234,26 +804,63 @@ impl<F: FieldExt> ExecutionGadget<F> for CopyToMemoryGadget<F> {
 
         let num_bytes_copied =
             selectors.iter().fold(0, |acc, s| acc + (*s as u64));
+        let copied_size = if is_word_aligned {
+            copied_words * 32
+        } else {
+            num_bytes_copied
+        };
         self.finish_gadget.assign(
             region,
             offset,
-            F::from(num_bytes_copied),
+            F::from(copied_size),
             F::from(*bytes_left),
         )?;
 
+        // Fold this step's rows into the Copy Circuit's log-derivative
+        // accumulator, continuing from `copy_acc_start`.
+        let alpha = block.copy_alpha;
+        let gamma = block.copy_gamma;
+        let mut acc = *copy_acc_start;
+        let acc_ext = *copy_acc_start_ext;
+        for row in &copy_rows {
+            if row.is_pad {
+                continue;
+            }
+            let c = gamma
+                + F::from(row.tag as u64)
+                + alpha * F::from(row.id)
+                + alpha.square() * F::from(row.addr)
+                + alpha.pow(&[3, 0, 0, 0]) * F::from(row.rw_counter as u64)
+                + alpha.pow(&[4, 0, 0, 0]) * F::from(row.byte as u64)
+                + alpha.pow(&[5, 0, 0, 0]) * F::from(row.is_read as u64);
+            acc += (alpha - c).invert().unwrap_or(F::zero());
+        }
+        self.copy_acc_start.assign(region, offset, Some(*copy_acc_start))?;
+        self.copy_acc_start_ext.assign(
+            region,
+            offset,
+            Some(*copy_acc_start_ext),
+        )?;
+        self.copy_acc.assign(region, offset, Some(acc))?;
+        self.copy_acc_ext.assign(region, offset, Some(acc_ext))?;
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 pub mod test {
-    use crate::evm_circuit::{
-        execution::memory_copy::MAX_COPY_BYTES,
-        step::ExecutionState,
-        test::{rand_bytes, run_test_circuit_incomplete_fixed_table},
-        util::RandomLinearCombination,
-        witness::{
-            Block, Bytecode, Call, ExecStep, GadgetExtraData, Rw, Transaction,
+    use crate::{
+        copy_circuit::{CopyCircuitRow, CopyDataType, CopyOobPolicy},
+        evm_circuit::{
+            execution::memory_copy::{MAX_COPY_BYTES, MAX_COPY_WORDS},
+            step::ExecutionState,
+            test::{rand_bytes, run_test_circuit_incomplete_fixed_table},
+            util::RandomLinearCombination,
+            witness::{
+                Block, Bytecode, Call, ExecStep, GadgetExtraData, Rw,
+                Transaction,
+            },
         },
     };
     use bus_mapping::{eth_types::ToLittleEndian, evm::OpcodeId};
@@ -261,6 +868,33 @@ pub mod test {
     use pairing::bn256::Fr as Fp;
     use std::collections::HashMap;
 
+    // Mirrors `CopyCircuitConfig::assign_region`'s log-derivative update, so
+    // tests can compute the accumulator a `CopyToMemory` step should carry
+    // without instantiating the Copy Circuit itself.
+    fn fold_copy_fingerprint(
+        acc: Fp,
+        alpha: Fp,
+        gamma: Fp,
+        rows: &[CopyCircuitRow],
+    ) -> Fp {
+        rows.iter().filter(|row| !row.is_pad).fold(acc, |acc, row| {
+            let c = gamma
+                + Fp::from(row.tag as u64)
+                + alpha * Fp::from(row.id)
+                + alpha.square() * Fp::from(row.addr)
+                + alpha.pow(&[3, 0, 0, 0]) * Fp::from(row.rw_counter as u64)
+                + alpha.pow(&[4, 0, 0, 0]) * Fp::from(row.byte as u64)
+                + alpha.pow(&[5, 0, 0, 0]) * Fp::from(row.is_read as u64);
+            acc + (alpha - c).invert().unwrap_or(Fp::zero())
+        })
+    }
+
+    // Sources whose bytes are read via a memory-like rw (as opposed to a
+    // dedicated table such as tx calldata or bytecode).
+    fn reads_from_rw(tag: CopyDataType) -> bool {
+        matches!(tag, CopyDataType::Memory | CopyDataType::ReturnData)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn make_memory_copy_step(
         call_id: usize,
@@ -268,37 +902,154 @@ pub mod test {
         dst_addr: u64,
         src_addr_end: u64,
         bytes_left: usize,
-        from_tx: bool,
+        src_tag: CopyDataType,
+        src_id: u64,
         program_counter: u64,
         stack_pointer: usize,
         memory_size: u64,
         rw_counter: usize,
         rws: &mut Vec<Rw>,
+        // The whole copy's source range as it stood before any step of this
+        // copy ran, keyed by address. Every read below — including reads by
+        // a later step of the same multi-step copy — is served straight out
+        // of this untouched snapshot rather than from bytes another step
+        // already wrote to `dst_addr`, mirroring MCOPY's `memmove` semantics
+        // (EIP-5656): every source byte is logically read before any
+        // destination byte is written, for the whole copy, not just one
+        // step. This also makes these fixtures overlap-direction agnostic:
+        // neither a forward (`dst < src`) nor a backward (`dst > src`)
+        // overlapping MCOPY ever observes its own in-progress writes.
         bytes_map: &HashMap<u64, u8>,
-    ) -> (ExecStep, usize) {
+        oob_policy: CopyOobPolicy,
+        alpha: Fp,
+        gamma: Fp,
+        copy_acc_start: Fp,
+    ) -> (ExecStep, usize, Fp, usize) {
         let mut selectors = vec![0u8; MAX_COPY_BYTES];
         let mut rw_offset: usize = 0;
         let rw_idx_start = rws.len();
+        let mut copy_rows = Vec::new();
+
+        // Mirrors `is_word_aligned`'s derivation in `assign_exec_step`: a
+        // step takes the word-granular fast path, instead of the per-byte
+        // loop below, whenever both addresses are 32-byte aligned, a whole
+        // word remains, and the copy isn't a backward-overlapping MCOPY
+        // (`is_overlap_backward`).
+        let is_overlap_backward = matches!(src_tag, CopyDataType::Memory)
+            && dst_addr > src_addr
+            && dst_addr < src_addr + bytes_left as u64;
+        let is_word_aligned = !is_overlap_backward
+            && src_addr % 32 == 0
+            && dst_addr % 32 == 0
+            && bytes_left >= 32
+            && src_addr + 32 <= src_addr_end;
+        // Mirrors `oob_violated`'s derivation in `configure`: a `Fault`
+        // source (RETURNDATACOPY) whose remaining range runs past
+        // `src_addr_end` copies nothing at all this step and instead hands
+        // off to `ErrorReturnDataOutOfBound`, rather than zero-padding the
+        // way a `ZeroPad` source (CALLDATACOPY/CODECOPY) would.
+        let oob_violated = matches!(oob_policy, CopyOobPolicy::Fault)
+            && src_addr + bytes_left as u64 > src_addr_end;
+        let copied_size = if oob_violated {
+            0
+        } else if is_word_aligned {
+            let words_in_bounds = ((src_addr_end - src_addr) / 32) as usize;
+            let copied_words = std::cmp::min(
+                MAX_COPY_WORDS,
+                std::cmp::min(bytes_left / 32, words_in_bounds),
+            );
+            for w in 0..copied_words {
+                let word_src_addr = src_addr + (w as u64) * 32;
+                let word_dst_addr = dst_addr + (w as u64) * 32;
+                let word: Vec<u8> = (0..32)
+                    .map(|k| {
+                        let addr = word_src_addr + k as u64;
+                        assert!(bytes_map.contains_key(&addr));
+                        bytes_map[&addr]
+                    })
+                    .collect();
+                if reads_from_rw(src_tag) {
+                    rws.push(Rw::MemoryWord {
+                        rw_counter: rw_counter + rw_offset,
+                        is_write: false,
+                        call_id,
+                        memory_address: word_src_addr,
+                        word: word.clone().try_into().unwrap(),
+                    });
+                    rw_offset += 1;
+                }
+                let read_rw_counter = rw_counter + rw_offset;
+                // Mirrors the production fix: the destination write is
+                // always a memory write and always consumes a fresh
+                // rw_counter, regardless of `src_tag`.
+                rws.push(Rw::MemoryWord {
+                    rw_counter: rw_counter + rw_offset,
+                    is_write: true,
+                    call_id,
+                    memory_address: word_dst_addr,
+                    word: word.clone().try_into().unwrap(),
+                });
+                rw_offset += 1;
+                let write_rw_counter = rw_counter + rw_offset;
+                for (k, byte) in word.iter().enumerate() {
+                    copy_rows.push(CopyCircuitRow {
+                        tag: src_tag,
+                        id: src_id,
+                        addr: word_src_addr + k as u64,
+                        rw_counter: read_rw_counter,
+                        byte: *byte,
+                        is_read: true,
+                        is_pad: false,
+                    });
+                    copy_rows.push(CopyCircuitRow {
+                        tag: CopyDataType::Memory,
+                        id: call_id as u64,
+                        addr: word_dst_addr + k as u64,
+                        rw_counter: write_rw_counter,
+                        byte: *byte,
+                        is_read: false,
+                        is_pad: false,
+                    });
+                }
+            }
+            copied_words * 32
+        } else {
+            std::cmp::min(bytes_left, MAX_COPY_BYTES)
+        };
         for (idx, selector) in selectors.iter_mut().enumerate() {
+            if oob_violated || is_word_aligned {
+                break;
+            }
             if idx < bytes_left {
                 *selector = 1;
                 let addr = src_addr + idx as u64;
-                let byte = if addr < src_addr_end {
+                let in_bounds = addr < src_addr_end;
+                let byte = if in_bounds {
                     assert!(bytes_map.contains_key(&addr));
-                    if !from_tx {
+                    let byte = bytes_map[&addr];
+                    if reads_from_rw(src_tag) {
                         rws.push(Rw::Memory {
                             rw_counter: rw_counter + rw_offset,
                             is_write: false,
                             call_id,
                             memory_address: src_addr + idx as u64,
-                            byte: bytes_map[&addr],
+                            byte,
                         });
                         rw_offset += 1;
                     }
-                    bytes_map[&addr]
+                    byte
                 } else {
                     0
                 };
+                copy_rows.push(CopyCircuitRow {
+                    tag: src_tag,
+                    id: src_id,
+                    addr,
+                    rw_counter: rw_counter + rw_offset,
+                    byte,
+                    is_read: true,
+                    is_pad: !in_bounds,
+                });
                 rws.push(Rw::Memory {
                     rw_counter: rw_counter + rw_offset,
                     is_write: true,
@@ -306,17 +1057,32 @@ pub mod test {
                     memory_address: dst_addr + idx as u64,
                     byte,
                 });
+                copy_rows.push(CopyCircuitRow {
+                    tag: CopyDataType::Memory,
+                    id: call_id as u64,
+                    addr: dst_addr + idx as u64,
+                    rw_counter: rw_counter + rw_offset + 1,
+                    byte,
+                    is_read: false,
+                    is_pad: false,
+                });
                 rw_offset += 1;
             }
         }
         let rw_idx_end = rws.len();
+        let copy_acc_end =
+            fold_copy_fingerprint(copy_acc_start, alpha, gamma, &copy_rows);
         let extra_data = GadgetExtraData::CopyToMemory {
             src_addr,
             dst_addr,
             bytes_left: bytes_left as u64,
             src_addr_end,
-            from_tx,
+            src_tag,
+            src_id,
             selectors,
+            oob_policy,
+            copy_acc_start,
+            copy_acc_start_ext: Fp::zero(),
         };
         let step = ExecStep {
             execution_state: ExecutionState::CopyToMemory,
@@ -329,7 +1095,7 @@ pub mod test {
             extra_data: Some(extra_data),
             ..Default::default()
         };
-        (step, rw_offset)
+        (step, rw_offset, copy_acc_end, copied_size)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -340,39 +1106,64 @@ pub mod test {
         src_addr: u64,
         dst_addr: u64,
         length: usize,
-        from_tx: bool,
+        src_tag: CopyDataType,
+        src_id: u64,
         program_counter: u64,
         stack_pointer: usize,
         memory_size: u64,
+        oob_policy: CopyOobPolicy,
         rw_counter: &mut usize,
         rws: &mut Vec<Rw>,
         steps: &mut Vec<ExecStep>,
-    ) {
+        alpha: Fp,
+        gamma: Fp,
+    ) -> Fp {
         let buffer_addr_end = buffer_addr + buffer.len() as u64;
+        // Frozen once here, before any step runs, and never mutated: this is
+        // the whole copy's pre-copy source snapshot every step's reads draw
+        // from (see `make_memory_copy_step`'s `bytes_map` doc).
         let bytes_map = (buffer_addr..buffer_addr_end)
             .zip(buffer.iter().copied())
             .collect();
 
         let mut copied = 0;
-        while copied < length {
-            let (step, rw_offset) = make_memory_copy_step(
+        let mut copy_acc = Fp::zero();
+        loop {
+            let (step, rw_offset, copy_acc_end, step_copied) = make_memory_copy_step(
                 call_id,
                 src_addr + copied as u64,
                 dst_addr + copied as u64,
                 buffer_addr_end,
                 length - copied,
-                from_tx,
+                src_tag,
+                src_id,
                 program_counter,
                 stack_pointer,
                 memory_size,
                 *rw_counter,
                 rws,
                 &bytes_map,
+                oob_policy,
+                alpha,
+                gamma,
+                copy_acc,
             );
+            let is_oob_fault_step = matches!(oob_policy, CopyOobPolicy::Fault)
+                && step_copied == 0
+                && copied < length;
             steps.push(step);
             *rw_counter += rw_offset;
-            copied += MAX_COPY_BYTES;
+            copy_acc = copy_acc_end;
+            copied += step_copied;
+            // A faulting step never makes progress (see `oob_violated` in
+            // `make_memory_copy_step`): it hands off to
+            // `ErrorReturnDataOutOfBound` instead, so looping further would
+            // spin forever.
+            if is_oob_fault_step || copied >= length {
+                break;
+            }
         }
+        copy_acc
     }
 
     fn test_ok_from_memory(
@@ -382,6 +1173,8 @@ pub mod test {
         length: usize,
     ) {
         let randomness = Fp::rand();
+        let copy_alpha = Fp::rand();
+        let copy_gamma = Fp::rand();
         let bytecode = Bytecode::new(vec![OpcodeId::STOP.as_u8()]);
         let call_id = 1;
         let mut rws = Vec::new();
@@ -397,13 +1190,17 @@ pub mod test {
             src_addr,
             dst_addr,
             length,
-            false,
+            CopyDataType::Memory,
+            call_id as u64,
             0,
             1024,
             memory_size,
+            CopyOobPolicy::ZeroPad,
             &mut rw_counter,
             &mut rws,
             &mut steps,
+            copy_alpha,
+            copy_gamma,
         );
 
         steps.push(ExecStep {
@@ -418,6 +1215,8 @@ pub mod test {
 
         let block = Block {
             randomness,
+            copy_alpha,
+            copy_gamma,
             txs: vec![Transaction {
                 id: 1,
                 calls: vec![Call {
@@ -447,6 +1246,8 @@ pub mod test {
         length: usize,
     ) {
         let randomness = Fp::rand();
+        let copy_alpha = Fp::rand();
+        let copy_gamma = Fp::rand();
         let bytecode =
             Bytecode::new(vec![OpcodeId::STOP.as_u8(), OpcodeId::STOP.as_u8()]);
         let call_id = 1;
@@ -463,13 +1264,17 @@ pub mod test {
             src_addr,
             dst_addr,
             length,
-            true,
+            CopyDataType::TxCalldata,
+            1,
             0,
             1024,
             memory_size,
+            CopyOobPolicy::ZeroPad,
             &mut rw_counter,
             &mut rws,
             &mut steps,
+            copy_alpha,
+            copy_gamma,
         );
 
         steps.push(ExecStep {
@@ -484,6 +1289,8 @@ pub mod test {
 
         let block = Block {
             randomness,
+            copy_alpha,
+            copy_gamma,
             txs: vec![Transaction {
                 id: 1,
                 call_data: calldata,
@@ -508,6 +1315,173 @@ pub mod test {
         assert_eq!(run_test_circuit_incomplete_fixed_table(block), Ok(()));
     }
 
+    // Exercises the CODECOPY path: the source is the running call's own
+    // bytecode, keyed by its code hash rather than a call id.
+    fn test_ok_from_bytecode(src_addr: u64, dst_addr: u64, length: usize) {
+        let randomness = Fp::rand();
+        let copy_alpha = Fp::rand();
+        let copy_gamma = Fp::rand();
+        let mut code = rand_bytes(64);
+        code[0] = OpcodeId::STOP.as_u8();
+        let bytecode = Bytecode::new(code);
+        let code_hash = u64::from_le_bytes(
+            bytecode.hash.to_le_bytes()[..8].try_into().unwrap(),
+        );
+        let call_id = 1;
+        let mut rws = Vec::new();
+        let mut rw_counter = 1;
+        let mut steps = Vec::new();
+        let memory_size = (dst_addr + length as u64 + 31) / 32;
+
+        make_memory_copy_steps(
+            call_id,
+            &bytecode.bytes,
+            0,
+            src_addr,
+            dst_addr,
+            length,
+            CopyDataType::Bytecode,
+            code_hash,
+            0,
+            1024,
+            memory_size,
+            CopyOobPolicy::ZeroPad,
+            &mut rw_counter,
+            &mut rws,
+            &mut steps,
+            copy_alpha,
+            copy_gamma,
+        );
+
+        steps.push(ExecStep {
+            execution_state: ExecutionState::STOP,
+            rw_counter,
+            program_counter: 0,
+            stack_pointer: 1024,
+            memory_size,
+            opcode: Some(OpcodeId::STOP),
+            ..Default::default()
+        });
+
+        let expected = {
+            let start = src_addr as usize;
+            let end = std::cmp::min(start + length, bytecode.bytes.len());
+            let mut expected = bytecode.bytes[start..end].to_vec();
+            expected.resize(length, 0);
+            expected
+        };
+        assert_eq!(written_bytes(&rws, dst_addr, length), expected);
+
+        let block = Block {
+            randomness,
+            copy_alpha,
+            copy_gamma,
+            txs: vec![Transaction {
+                id: 1,
+                calls: vec![Call {
+                    id: call_id,
+                    is_root: true,
+                    is_create: false,
+                    opcode_source:
+                        RandomLinearCombination::random_linear_combine(
+                            bytecode.hash.to_le_bytes(),
+                            randomness,
+                        ),
+                    ..Default::default()
+                }],
+                steps,
+                ..Default::default()
+            }],
+            rws,
+            bytecodes: vec![bytecode],
+        };
+        assert_eq!(run_test_circuit_incomplete_fixed_table(block), Ok(()));
+    }
+
+    // Exercises the RETURNDATACOPY path on its happy (in-bounds) branch: the
+    // source is a previous call's return data, keyed by the caller's call
+    // id. `copy_to_memory_oob_policy_returndata_reverts_calldata_pads`
+    // already covers the `Fault` (out-of-bounds) branch; this covers the
+    // branch that actually copies bytes, both via the per-byte path and,
+    // for a word-aligned length, the word-granular fast path.
+    fn test_ok_from_returndata(
+        src_addr: u64,
+        dst_addr: u64,
+        src_addr_end: u64,
+        length: usize,
+    ) {
+        let randomness = Fp::rand();
+        let copy_alpha = Fp::rand();
+        let copy_gamma = Fp::rand();
+        let bytecode = Bytecode::new(vec![OpcodeId::STOP.as_u8()]);
+        let call_id = 1;
+        let caller_call_id = 2;
+        let mut rws = Vec::new();
+        let mut rw_counter = 1;
+        let mut steps = Vec::new();
+        let buffer = rand_bytes((src_addr_end - src_addr) as usize);
+        let memory_size = (dst_addr + length as u64 + 31) / 32;
+
+        make_memory_copy_steps(
+            call_id,
+            &buffer,
+            src_addr,
+            src_addr,
+            dst_addr,
+            length,
+            CopyDataType::ReturnData,
+            caller_call_id as u64,
+            0,
+            1024,
+            memory_size,
+            CopyOobPolicy::Fault,
+            &mut rw_counter,
+            &mut rws,
+            &mut steps,
+            copy_alpha,
+            copy_gamma,
+        );
+
+        let mut expected = buffer;
+        expected.resize(length, 0);
+        assert_eq!(written_bytes(&rws, dst_addr, length), expected);
+
+        steps.push(ExecStep {
+            execution_state: ExecutionState::STOP,
+            rw_counter,
+            program_counter: 0,
+            stack_pointer: 1024,
+            memory_size,
+            opcode: Some(OpcodeId::STOP),
+            ..Default::default()
+        });
+
+        let block = Block {
+            randomness,
+            copy_alpha,
+            copy_gamma,
+            txs: vec![Transaction {
+                id: 1,
+                calls: vec![Call {
+                    id: call_id,
+                    is_root: true,
+                    is_create: false,
+                    opcode_source:
+                        RandomLinearCombination::random_linear_combine(
+                            bytecode.hash.to_le_bytes(),
+                            randomness,
+                        ),
+                    ..Default::default()
+                }],
+                steps,
+                ..Default::default()
+            }],
+            rws,
+            bytecodes: vec![bytecode],
+        };
+        assert_eq!(run_test_circuit_incomplete_fixed_table(block), Ok(()));
+    }
+
     #[test]
     fn copy_to_memory_simple() {
         test_ok_from_memory(0x40, 0xA0, 0x70, 5);
@@ -526,4 +1500,273 @@ pub mod test {
         test_ok_from_tx(32, 5, 0x40, 45);
         test_ok_from_tx(32, 40, 0x40, 5);
     }
+
+    #[test]
+    fn copy_to_memory_from_bytecode() {
+        test_ok_from_bytecode(0, 0x40, 32);
+    }
+
+    #[test]
+    fn copy_to_memory_from_returndata() {
+        test_ok_from_returndata(0x40, 0xA0, 0x70, 5);
+        test_ok_from_returndata(0x20, 0xA0, 0x80, 80);
+    }
+
+    #[test]
+    fn copy_to_memory_from_returndata_word_aligned() {
+        test_ok_from_returndata(0x100, 0x200, 0x100 + 256, 256);
+    }
+
+    // MCOPY-style memory-to-memory copies, including the overlapping
+    // ranges a real `is_overlap_backward` hazard can arise from. Each spans
+    // several MAX_COPY_BYTES-sized steps so the hazard isn't confined to a
+    // single step's byte window.
+    #[test]
+    fn copy_to_memory_overlap_full() {
+        // src_addr == dst_addr: a no-op identity copy.
+        test_ok_from_memory(0x100, 0x100, 0x100 + 300, 300);
+    }
+
+    #[test]
+    fn copy_to_memory_overlap_partial_forward() {
+        // dst_addr < src_addr: overlapping, but a write can only ever land
+        // on a source byte this copy has already read.
+        test_ok_from_memory(0x100, 0xE2, 0x100 + 300, 300);
+    }
+
+    #[test]
+    fn copy_to_memory_overlap_partial_backward() {
+        // dst_addr > src_addr and the ranges overlap: the classic
+        // `memmove` hazard `is_overlap_backward` is meant to catch, where a
+        // write can land on a source byte not yet read.
+        test_ok_from_memory(0x100, 0x120, 0x100 + 300, 300);
+    }
+
+    // Collects the final value written to each address in
+    // `[dst_addr, dst_addr + length)` by replaying a copy's `rws`, so a test
+    // can check the *values* MCOPY actually produced independently of
+    // whatever internal snapshot/shadowing the witness generator used to get
+    // there.
+    fn written_bytes(rws: &[Rw], dst_addr: u64, length: usize) -> Vec<u8> {
+        let mut out = vec![0u8; length];
+        for rw in rws {
+            match *rw {
+                Rw::Memory { is_write: true, memory_address, byte, .. }
+                    if memory_address >= dst_addr
+                        && memory_address < dst_addr + length as u64 =>
+                {
+                    out[(memory_address - dst_addr) as usize] = byte;
+                }
+                Rw::MemoryWord { is_write: true, memory_address, word, .. } => {
+                    for (k, byte) in word.iter().enumerate() {
+                        let addr = memory_address + k as u64;
+                        if addr >= dst_addr && addr < dst_addr + length as u64
+                        {
+                            out[(addr - dst_addr) as usize] = *byte;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+
+    // A real `memmove`'s destination ends up holding exactly the *original*
+    // (pre-copy) source bytes, no matter which way `src_addr`/`dst_addr`
+    // overlap. This checks that directly against `written_bytes`, rather
+    // than only checking the circuit accepts the witness (which a witness
+    // built from a self-consistent but wrong value would also do).
+    #[test]
+    fn copy_to_memory_overlap_produces_correct_memmove() {
+        let length = 300;
+        let buffer = rand_bytes(length);
+        let alpha = Fp::rand();
+        let gamma = Fp::rand();
+        let call_id = 1;
+
+        for (src_addr, dst_addr) in [
+            (0x100u64, 0x120u64), // backward overlap: dst_addr > src_addr
+            (0x100u64, 0xE2u64),  // forward overlap: dst_addr < src_addr
+        ] {
+            let mut rws = Vec::new();
+            let mut rw_counter = 1;
+            let mut steps = Vec::new();
+            make_memory_copy_steps(
+                call_id,
+                &buffer,
+                src_addr,
+                src_addr,
+                dst_addr,
+                length,
+                CopyDataType::Memory,
+                call_id as u64,
+                0,
+                1024,
+                (dst_addr + length as u64 + 31) / 32,
+                CopyOobPolicy::ZeroPad,
+                &mut rw_counter,
+                &mut rws,
+                &mut steps,
+                alpha,
+                gamma,
+            );
+            assert_eq!(
+                written_bytes(&rws, dst_addr, length),
+                buffer,
+                "src_addr={src_addr:#x} dst_addr={dst_addr:#x}",
+            );
+        }
+    }
+
+    // `src_addr`/`dst_addr` are word-aligned but `src_addr_end` falls
+    // mid-word: the word path must not select a word straddling that
+    // boundary (it has no zero-pad handling of its own), so the tail past
+    // the first in-bounds word has to fall back to the per-byte path,
+    // which zero-pads. Exercises the `word_oob_cmp` gating added alongside
+    // the `is_word_aligned`/`src_addr_end` fix.
+    #[test]
+    fn copy_to_memory_word_aligned_oob_falls_back_to_byte_path() {
+        test_ok_from_memory(0x200, 0x400, 0x200 + 40, 64);
+    }
+
+    #[test]
+    fn copy_to_memory_word_aligned() {
+        // src_addr/dst_addr both 32-byte aligned and the length is a whole
+        // number of words: every step should take the word-granular fast
+        // path rather than falling back to the per-byte `buffer_reader`.
+        test_ok_from_memory(0x100, 0x200, 0x100 + 256, 256);
+    }
+
+    // Word-granular copies emit one rw pair per 32-byte word instead of one
+    // per byte, so a large aligned MCOPY/CALLDATACOPY should use far fewer
+    // `rws` entries than the equivalent unaligned (byte-by-byte) copy of the
+    // same length. This is the row-count savings the fast path exists for.
+    #[test]
+    fn copy_to_memory_word_aligned_cuts_rw_rows() {
+        let length = 320usize;
+
+        let rws_for = |src_addr: u64, dst_addr: u64| -> usize {
+            let alpha = Fp::rand();
+            let gamma = Fp::rand();
+            let call_id = 1;
+            let mut rws = Vec::new();
+            let mut rw_counter = 1;
+            let mut steps = Vec::new();
+            let buffer_addr = src_addr;
+            let buffer = rand_bytes(length + 32);
+            make_memory_copy_steps(
+                call_id,
+                &buffer,
+                buffer_addr,
+                src_addr,
+                dst_addr,
+                length,
+                CopyDataType::Memory,
+                call_id as u64,
+                0,
+                1024,
+                (dst_addr + length as u64 + 31) / 32,
+                CopyOobPolicy::ZeroPad,
+                &mut rw_counter,
+                &mut rws,
+                &mut steps,
+                alpha,
+                gamma,
+            );
+            rws.len()
+        };
+
+        // Aligned: every step should take the word path.
+        let aligned_rows = rws_for(0x200, 0x400);
+        // Unaligned by one byte: every step falls back to the byte path.
+        let unaligned_rows = rws_for(0x201, 0x401);
+
+        assert!(
+            aligned_rows < unaligned_rows,
+            "word-aligned copy should use fewer rw rows: {} vs {}",
+            aligned_rows,
+            unaligned_rows,
+        );
+    }
+
+    // RETURNDATACOPY must revert when asked to read past the end of its
+    // source buffer (`CopyOobPolicy::Fault`), since `RETURNDATASIZE` is the
+    // only way to learn that buffer's length. The same out-of-bounds read
+    // via CALLDATACOPY (`CopyOobPolicy::ZeroPad`) instead completes,
+    // zero-padding the byte past the end.
+    #[test]
+    fn copy_to_memory_oob_policy_returndata_reverts_calldata_pads() {
+        let alpha = Fp::rand();
+        let gamma = Fp::rand();
+        let call_id = 1;
+        let buffer = rand_bytes(32);
+        let src_addr = 0;
+        let dst_addr = 0x40;
+        // One byte past the end of the 32-byte buffer.
+        let length = 33;
+
+        let mut fault_rws = Vec::new();
+        let mut fault_rw_counter = 1;
+        let mut fault_steps = Vec::new();
+        make_memory_copy_steps(
+            call_id,
+            &buffer,
+            src_addr,
+            src_addr,
+            dst_addr,
+            length,
+            CopyDataType::ReturnData,
+            call_id as u64,
+            0,
+            1024,
+            (dst_addr + length as u64 + 31) / 32,
+            CopyOobPolicy::Fault,
+            &mut fault_rw_counter,
+            &mut fault_rws,
+            &mut fault_steps,
+            alpha,
+            gamma,
+        );
+        // The violation is caught on the very first (and only) step: it
+        // copies nothing, and `assign_exec_step`/`configure` route the next
+        // state to `ErrorReturnDataOutOfBound` instead of looping back into
+        // another `CopyToMemory` step.
+        assert_eq!(fault_steps.len(), 1);
+        assert!(fault_rws.is_empty());
+        fault_steps.push(ExecStep {
+            execution_state: ExecutionState::ErrorReturnDataOutOfBound,
+            rw_counter: fault_rw_counter,
+            program_counter: 0,
+            stack_pointer: 1024,
+            ..Default::default()
+        });
+
+        let mut pad_rws = Vec::new();
+        let mut pad_rw_counter = 1;
+        let mut pad_steps = Vec::new();
+        make_memory_copy_steps(
+            call_id,
+            &buffer,
+            src_addr,
+            src_addr,
+            dst_addr,
+            length,
+            CopyDataType::TxCalldata,
+            1,
+            0,
+            1024,
+            (dst_addr + length as u64 + 31) / 32,
+            CopyOobPolicy::ZeroPad,
+            &mut pad_rw_counter,
+            &mut pad_rws,
+            &mut pad_steps,
+            alpha,
+            gamma,
+        );
+        // The zero-pad policy completes the whole copy rather than
+        // faulting: one word-aligned step for the 32 in-bounds bytes, then
+        // one byte step reading the byte past the buffer's end as zero.
+        assert_eq!(pad_steps.len(), 2);
+    }
 }